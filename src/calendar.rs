@@ -3,6 +3,8 @@ use std::cmp::Ordering;
 use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 
+use crate::calendar_system::{CalendarSystem, Gregorian};
+
 ///
 /// The LSTC Calendar is used to create a collection of patterns to perform
 /// matches against, thereby allowing us to classify dates into more useful
@@ -10,6 +12,7 @@ use serde::{Deserialize, Serialize};
 /// ```
 /// use chrono::{NaiveDate, Weekday};
 /// use lstc_calendar::{Calendar, CalendarEntry};
+/// #[derive(Clone)]
 /// enum Day {
 ///     Workday,
 ///     Weekend,
@@ -41,13 +44,48 @@ pub struct CalendarEntry<T> {
     pub month: Option<u32>,
     /// The day of the month this entry applies to
     pub day: Option<u32>,
-    /// The week of the month this entry applies to
+    /// The week of the month this entry applies to, counted against
+    /// `week_start`. A positive value counts from the start of the month:
+    /// week 1 runs from the 1st up to and including the first `week_start`
+    /// day, and each later week ends on the next `week_start` day. A
+    /// negative value counts back from the end (`-1` is the last week):
+    /// the last week runs from the last `week_start` day to the end of the
+    /// month. The two directions do not split the month into the same weeks.
     pub week_of_month: Option<i32>,
+    /// The weekday used to divide the month into weeks when computing
+    /// `week_of_month`.  Defaults to `Weekday::Mon`.
+    #[serde(default = "default_week_start")]
+    pub week_start: Weekday,
     /// The weekdays this entry applies to
     pub days_of_week: Option<Vec<Weekday>>,
+    /// When set alongside a single `days_of_week` entry, this is the nth
+    /// (or, if negative, last-from-end) occurrence of that weekday within
+    /// the month, counted by stepping through occurrences of the weekday
+    /// itself. Unlike `week_of_month`, this is independent of
+    /// `week_start`. Set via [`CalendarEntry::nth_weekday`].
+    pub nth_weekday: Option<i32>,
     /// The offset can be used to model lieu days, where a holiday would
     /// normally fall on a weekend and a replacement should be offered.
     pub offset: i32,
+    /// When set, this entry matches the date that is Easter Sunday of that
+    /// date's year plus this many days, instead of a fixed `month`/`day`.
+    /// Movable feasts such as Good Friday (`-2`) or Whit Monday (`50`) are
+    /// modelled this way.
+    pub easter_offset: Option<i32>,
+    /// The calendar system this entry's `year`/`month`/`day` are expressed
+    /// in, e.g. [`IslamicCalendar`] for "1 Shawwal". Defaults to
+    /// [`Gregorian`]. Not (de)serialized: entries loaded from a
+    /// [`crate::CalendarSpec`] always use Gregorian.
+    #[serde(skip, default = "default_calendar_system")]
+    pub calendar_system: Box<dyn CalendarSystem>,
+}
+
+fn default_week_start() -> Weekday {
+    Weekday::Mon
+}
+
+fn default_calendar_system() -> Box<dyn CalendarSystem> {
+    Box::new(Gregorian)
 }
 
 impl<T> std::default::Default for Calendar<T> {
@@ -63,14 +101,77 @@ impl<T> Calendar<T> {
         }
     }
 
+    ///
+    /// Add a Calendar Entry into the Calendar.  Ordering is important when
+    /// classifying dates.  The oldest entry is matched last, therefore allowing
+    /// greater specificity in further entries.
+    ///
+    pub fn add(&mut self, entry: CalendarEntry<T>) {
+        self.entries.insert(0, entry)
+    }
+
+    ///
+    /// Lazily walk `start..=end`, classifying each day and yielding only the
+    /// dates that match an entry.  Entries are evaluated in the same order as
+    /// `classify`, so the first matching entry's classification is returned.
+    /// Useful for generating exception-date lists, e.g. "every bank holiday
+    /// in 2024", without materialising the whole range up front.
+    ///
+    pub fn occurrences(&self, start: NaiveDate, end: NaiveDate) -> Occurrences<'_, T> {
+        Occurrences {
+            calendar: self,
+            current: start,
+            end,
+        }
+    }
+}
+
+///
+/// Lazy iterator over the dates in a range that classify to something,
+/// returned by [`Calendar::occurrences`].
+///
+pub struct Occurrences<'a, T> {
+    calendar: &'a Calendar<T>,
+    current: NaiveDate,
+    end: NaiveDate,
+}
+
+impl<'a, T> Occurrences<'a, T> {
+    ///
+    /// Reposition the iterator to resume scanning from `date`, so a caller
+    /// can page through future occurrences without re-scanning from the
+    /// original start.
+    ///
+    pub fn starting_at(mut self, date: NaiveDate) -> Self {
+        self.current = date;
+        self
+    }
+}
+
+impl<'a, T> Iterator for Occurrences<'a, T> {
+    type Item = (NaiveDate, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current <= self.end {
+            let date = self.current;
+            self.current += Duration::days(1);
+            if let Some(entry) = self.calendar.entries.iter().find(|e| e.matches(date)) {
+                return Some((date, &entry.classification));
+            }
+        }
+        None
+    }
+}
+
+impl<T: Clone> Calendar<T> {
     ///
     /// Classifify a given date, based on the entries added.  Entries are
     /// evaluated in reverse order, making the latest entries evaludated first.
     ///
-    pub fn classify(self, date: NaiveDate) -> Option<T> {
-        for e in self.entries {
+    pub fn classify(&self, date: NaiveDate) -> Option<T> {
+        for e in &self.entries {
             if e.matches(date) {
-                return Some(e.classification);
+                return Some(e.classification.clone());
             }
         }
         None
@@ -79,44 +180,239 @@ impl<T> Calendar<T> {
     ///
     /// Helper function that classifies a date from it's ymd portions.
     ///
-    pub fn classify_ymd(self, year: i32, month: u32, day: u32) -> Option<T> {
+    pub fn classify_ymd(&self, year: i32, month: u32, day: u32) -> Option<T> {
         let d = NaiveDate::from_ymd_opt(year, month, day).unwrap();
         self.classify(d)
     }
 
     ///
-    /// Add a Calendar Entry into the Calendar.  Ordering is important when
-    /// classifying dates.  The oldest entry is matched last, therefore allowing
-    /// greater specificity in further entries.
+    /// A date is a business day if it either classifies to nothing, or
+    /// classifies to something the caller doesn't consider non-working
+    /// (e.g. a weekend or a bank holiday).
     ///
-    pub fn add(&mut self, entry: CalendarEntry<T>) {
-        self.entries.insert(0, entry)
+    pub fn is_business_day<F: Fn(&T) -> bool>(&self, date: NaiveDate, is_non_working: F) -> bool {
+        match self.classify(date) {
+            Some(c) => !is_non_working(&c),
+            None => true,
+        }
     }
+
+    ///
+    /// Walk forward a day at a time until a business day is found.
+    ///
+    pub fn next_business_day<F: Fn(&T) -> bool>(
+        &self,
+        date: NaiveDate,
+        is_non_working: F,
+    ) -> NaiveDate {
+        let mut d = date + Duration::days(1);
+        while !self.is_business_day(d, &is_non_working) {
+            d += Duration::days(1);
+        }
+        d
+    }
+
+    ///
+    /// Walk backward a day at a time until a business day is found.
+    ///
+    pub fn prev_business_day<F: Fn(&T) -> bool>(
+        &self,
+        date: NaiveDate,
+        is_non_working: F,
+    ) -> NaiveDate {
+        let mut d = date - Duration::days(1);
+        while !self.is_business_day(d, &is_non_working) {
+            d -= Duration::days(1);
+        }
+        d
+    }
+
+    ///
+    /// Roll a date that falls on a non-business day to a business day,
+    /// following the given `DayAdjust` rule.  Dates that are already
+    /// business days are returned unchanged.
+    ///
+    pub fn adjust<F: Fn(&T) -> bool>(
+        &self,
+        date: NaiveDate,
+        rule: DayAdjust,
+        is_non_working: F,
+    ) -> NaiveDate {
+        if self.is_business_day(date, &is_non_working) {
+            return date;
+        }
+
+        match rule {
+            DayAdjust::None => date,
+            DayAdjust::Following => self.next_business_day(date, is_non_working),
+            DayAdjust::Preceding => self.prev_business_day(date, is_non_working),
+            DayAdjust::Modified => {
+                let next = self.next_business_day(date, &is_non_working);
+                if next.month() == date.month() {
+                    next
+                } else {
+                    self.prev_business_day(date, is_non_working)
+                }
+            }
+            DayAdjust::ModifiedPreceding => {
+                let prev = self.prev_business_day(date, &is_non_working);
+                if prev.month() == date.month() {
+                    prev
+                } else {
+                    self.next_business_day(date, is_non_working)
+                }
+            }
+        }
+    }
+}
+
+///
+/// Rules for rolling a non-working day to the nearest business day, as used
+/// by settlement and payroll calculations.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DayAdjust {
+    /// Leave the date as-is, even if it isn't a business day.
+    None,
+    /// Roll forward to the next business day.
+    Following,
+    /// Roll backward to the previous business day.
+    Preceding,
+    /// Roll forward, unless that crosses into the next month, in which
+    /// case roll backward instead.
+    Modified,
+    /// Roll backward, unless that crosses into the previous month, in
+    /// which case roll forward instead.
+    ModifiedPreceding,
+}
+
+///
+/// Computes the Gregorian date of Easter Sunday for a given year, using the
+/// Anonymous Gregorian (Meeus/Jones/Butcher) algorithm.
+///
+fn easter_sunday(year: i32) -> NaiveDate {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = ((h + l - 7 * m + 114) % 31) + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32).unwrap()
+}
+
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap()
+        .checked_add_months(Months::new(1))
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+///
+/// Counts which week of the month `date` falls in, counting from the start:
+/// one more than the number of `week_start` days before `date` in the month.
+/// A `week_start` day therefore closes its week rather than opening a new
+/// one, so week 1 runs up to and including the first `week_start` day.
+///
+fn week_of_month_from_start(date: NaiveDate, week_start: Weekday) -> i32 {
+    let weeks_passed = (1..date.day())
+        .filter(|&d| {
+            NaiveDate::from_ymd_opt(date.year(), date.month(), d)
+                .unwrap()
+                .weekday()
+                == week_start
+        })
+        .count();
+    weeks_passed as i32 + 1
+}
+
+///
+/// Counts which week of the month `date` falls in, counting from the end,
+/// where `1` is the last week, `2` the one before it, and so on.
+///
+fn week_of_month_from_end(date: NaiveDate, week_start: Weekday) -> i32 {
+    let last_day = last_day_of_month(date.year(), date.month());
+    let weeks_remaining = ((date.day() + 1)..=last_day)
+        .filter(|&d| {
+            NaiveDate::from_ymd_opt(date.year(), date.month(), d)
+                .unwrap()
+                .weekday()
+                == week_start
+        })
+        .count();
+    weeks_remaining as i32 + 1
+}
+
+///
+/// Counts which occurrence within the month `date` is of its own weekday,
+/// counting from the start (the 1st is always occurrence `1`).
+///
+fn weekday_ordinal_from_start(date: NaiveDate) -> i32 {
+    let weekday = date.weekday();
+    let occurrences_so_far = (1..=date.day())
+        .filter(|&d| {
+            NaiveDate::from_ymd_opt(date.year(), date.month(), d)
+                .unwrap()
+                .weekday()
+                == weekday
+        })
+        .count();
+    occurrences_so_far as i32
+}
+
+///
+/// Counts which occurrence within the month `date` is of its own weekday,
+/// counting from the end (`1` is the last occurrence, `2` the one before
+/// it, and so on).
+///
+fn weekday_ordinal_from_end(date: NaiveDate) -> i32 {
+    let weekday = date.weekday();
+    let last_day = last_day_of_month(date.year(), date.month());
+    let occurrences_remaining = (date.day()..=last_day)
+        .filter(|&d| {
+            NaiveDate::from_ymd_opt(date.year(), date.month(), d)
+                .unwrap()
+                .weekday()
+                == weekday
+        })
+        .count();
+    occurrences_remaining as i32
 }
 
 impl<T> CalendarEntry<T> {
     fn matches(&self, date: NaiveDate) -> bool {
         let date = date - Duration::days(self.offset as i64);
-        let year = date.year();
-        let month = date.month0() + 1;
-        let d0 = date.day0();
-        let day = d0 + 1;
-        let weekday = date.weekday();
+        let (year, month, day, weekday) = self.calendar_system.convert(date);
+
+        let date_matches = match self.easter_offset {
+            // Easter is always a Gregorian/Christian computation, regardless
+            // of the entry's calendar system.
+            Some(days) => date == easter_sunday(date.year()) + Duration::days(days as i64),
+            None => self.month.map_or(true, |m| m == month) && self.day.map_or(true, |d| d == day),
+        };
 
         self.year.map_or(true, |y| y == year)
-            && self.month.map_or(true, |m| m == month)
-            && self.day.map_or(true, |d| d == day)
+            && date_matches
             && self.week_of_month.map_or(true, |w| match w.cmp(&0) {
                 Ordering::Equal => false,
-                Ordering::Greater => w == ((d0 / 7) + 1) as i32,
-                Ordering::Less => {
-                    let som = NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
-                        .unwrap()
-                        .checked_add_months(Months::new(1))
-                        .unwrap();
-                    let diff = (som - date).num_days();
-                    -w == (((diff - 1) / 7) + 1) as i32
-                }
+                Ordering::Greater => w == week_of_month_from_start(date, self.week_start),
+                Ordering::Less => -w == week_of_month_from_end(date, self.week_start),
+            })
+            && self.nth_weekday.map_or(true, |n| match n.cmp(&0) {
+                Ordering::Equal => false,
+                Ordering::Greater => n == weekday_ordinal_from_start(date),
+                Ordering::Less => -n == weekday_ordinal_from_end(date),
             })
             && self
                 .days_of_week
@@ -139,11 +435,34 @@ impl<T> CalendarEntry<T> {
             month,
             day,
             week_of_month,
+            week_start: default_week_start(),
             days_of_week,
+            nth_weekday: None,
             offset,
+            easter_offset: None,
+            calendar_system: default_calendar_system(),
         }
     }
 
+    ///
+    /// Overrides the weekday a week is considered to start on when
+    /// computing `week_of_month`.
+    ///
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    ///
+    /// Overrides the calendar system this entry's `year`/`month`/`day` are
+    /// expressed in, so e.g. "1 Shawwal" can be matched against the
+    /// [`IslamicCalendar`] instead of the default [`Gregorian`] calendar.
+    ///
+    pub fn with_calendar_system(mut self, calendar_system: impl CalendarSystem + 'static) -> Self {
+        self.calendar_system = Box::new(calendar_system);
+        self
+    }
+
     pub fn all(classification: T) -> Self {
         Self::new(classification, None, None, None, None, None, 0)
     }
@@ -173,6 +492,90 @@ impl<T> CalendarEntry<T> {
             0,
         )
     }
+
+    ///
+    /// An entry that matches the date that is Easter Sunday of that date's
+    /// year plus `days` (e.g. `-2` for Good Friday, `1` for Easter Monday,
+    /// `50` for Whit Monday), computed with the Anonymous Gregorian
+    /// (Meeus/Jones/Butcher) algorithm.
+    ///
+    pub fn easter_offset(classification: T, days: i32) -> Self {
+        Self {
+            easter_offset: Some(days),
+            ..Self::new(classification, None, None, None, None, None, 0)
+        }
+    }
+
+    ///
+    /// An entry that matches the nth occurrence of `weekday` in `month`,
+    /// e.g. the first Monday of May.  A negative `n` counts from the end of
+    /// the month instead, so `-1` is the last occurrence, regardless of
+    /// whether that weekday falls four or five times in the month.
+    ///
+    pub fn nth_weekday(classification: T, month: u32, n: i32, weekday: Weekday) -> Self {
+        Self {
+            nth_weekday: Some(n),
+            ..Self::new(
+                classification,
+                None,
+                Some(month),
+                None,
+                None,
+                Some(vec![weekday]),
+                0,
+            )
+        }
+    }
+
+    ///
+    /// Lazily walk `start..=end`, yielding only the dates this entry matches
+    /// (respecting `offset`, `week_of_month` and `days_of_week`).  Useful for
+    /// enumerating every occurrence of a single pattern, e.g. "the next five
+    /// August bank holidays", without materialising a full year of dates.
+    ///
+    pub fn iter_between(&self, start: NaiveDate, end: NaiveDate) -> EntryOccurrences<'_, T> {
+        EntryOccurrences {
+            entry: self,
+            current: start,
+            end,
+        }
+    }
+}
+
+///
+/// Lazy iterator over the dates a single [`CalendarEntry`] matches within a
+/// range, returned by [`CalendarEntry::iter_between`].
+///
+pub struct EntryOccurrences<'a, T> {
+    entry: &'a CalendarEntry<T>,
+    current: NaiveDate,
+    end: NaiveDate,
+}
+
+impl<'a, T> EntryOccurrences<'a, T> {
+    ///
+    /// Reposition the iterator to resume scanning from `date`, so a caller
+    /// can page through future occurrences of this entry.
+    ///
+    pub fn starting_at(mut self, date: NaiveDate) -> Self {
+        self.current = date;
+        self
+    }
+}
+
+impl<'a, T> Iterator for EntryOccurrences<'a, T> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current <= self.end {
+            let date = self.current;
+            self.current += Duration::days(1);
+            if self.entry.matches(date) {
+                return Some(date);
+            }
+        }
+        None
+    }
 }
 
 #[cfg(test)]
@@ -180,6 +583,8 @@ mod tests {
     use super::*;
     use test_case::test_case;
 
+    use crate::calendar_system::IslamicCalendar;
+
     #[derive(PartialEq, Clone, Copy, Debug)]
     enum Day {
         Workday,
@@ -284,4 +689,276 @@ mod tests {
 
         assert_eq!(actual, Some(expected));
     }
+
+    fn uk_style_calendar() -> Calendar<Day> {
+        let mut subject = Calendar::<Day>::new();
+        subject.add(CalendarEntry::all(Day::Workday));
+        subject.add(CalendarEntry::days(
+            Day::Weekend,
+            vec![Weekday::Sat, Weekday::Sun],
+        ));
+        subject.add(CalendarEntry::ymd(
+            Day::Holiday("New Year's Day"),
+            None,
+            Some(1),
+            Some(1),
+        ));
+        subject
+    }
+
+    fn is_non_working(day: &Day) -> bool {
+        matches!(day, Day::Weekend | Day::Holiday(_))
+    }
+
+    #[test_case(2024, 1, 1, false; "New Year's Day is not a business day")]
+    #[test_case(2024, 1, 6, false; "Saturday is not a business day")]
+    #[test_case(2024, 1, 8, true; "Monday is a business day")]
+    fn test_is_business_day(y: i32, m: u32, d: u32, expected: bool) {
+        let subject = uk_style_calendar();
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(subject.is_business_day(date, is_non_working), expected);
+    }
+
+    #[test]
+    fn test_next_business_day_skips_weekend_and_holiday() {
+        let subject = uk_style_calendar();
+        // Friday 2023-12-29 is followed by a weekend and New Year's Day.
+        let friday = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+
+        let actual = subject.next_business_day(friday, is_non_working);
+
+        assert_eq!(actual, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_prev_business_day_skips_weekend() {
+        let subject = uk_style_calendar();
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+
+        let actual = subject.prev_business_day(monday, is_non_working);
+
+        assert_eq!(actual, NaiveDate::from_ymd_opt(2024, 1, 5).unwrap());
+    }
+
+    #[test_case(DayAdjust::None, 2023, 12, 31, 2023, 12, 31; "None leaves a Sunday unchanged")]
+    #[test_case(DayAdjust::Following, 2023, 12, 31, 2024, 1, 2; "Following rolls forward across New Year")]
+    #[test_case(DayAdjust::Preceding, 2023, 12, 31, 2023, 12, 29; "Preceding rolls back within December")]
+    #[test_case(DayAdjust::Modified, 2023, 12, 31, 2023, 12, 29; "Modified falls back to Preceding when Following would cross into January")]
+    #[test_case(DayAdjust::Modified, 2024, 1, 6, 2024, 1, 8; "Modified behaves like Following when the month doesn't change")]
+    #[test_case(DayAdjust::ModifiedPreceding, 2024, 1, 1, 2024, 1, 2; "ModifiedPreceding falls back to Following when Preceding would cross into December")]
+    #[test_case(DayAdjust::ModifiedPreceding, 2023, 12, 31, 2023, 12, 29; "ModifiedPreceding behaves like Preceding when the month doesn't change")]
+    fn test_adjust(rule: DayAdjust, sy: i32, sm: u32, sd: u32, ey: i32, em: u32, ed: u32) {
+        let subject = uk_style_calendar();
+        let date = NaiveDate::from_ymd_opt(sy, sm, sd).unwrap();
+
+        let actual = subject.adjust(date, rule, is_non_working);
+
+        assert_eq!(actual, NaiveDate::from_ymd_opt(ey, em, ed).unwrap());
+    }
+
+    #[test]
+    fn test_occurrences_yields_only_matching_dates() {
+        let subject = uk_style_calendar();
+        let start = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let actual: Vec<(NaiveDate, Day)> = subject
+            .occurrences(start, end)
+            .map(|(d, c)| (d, *c))
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                (NaiveDate::from_ymd_opt(2023, 12, 29).unwrap(), Day::Workday),
+                (NaiveDate::from_ymd_opt(2023, 12, 30).unwrap(), Day::Weekend),
+                (NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(), Day::Weekend),
+                (
+                    NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                    Day::Holiday("New Year's Day"),
+                ),
+                (NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(), Day::Workday),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_occurrences_starting_at_resumes_the_scan() {
+        let subject = uk_style_calendar();
+        let start = NaiveDate::from_ymd_opt(2023, 12, 29).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+
+        let actual: Vec<NaiveDate> = subject
+            .occurrences(start, end)
+            .starting_at(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .map(|(d, _)| d)
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_entry_iter_between_only_yields_its_own_matches() {
+        let entry = CalendarEntry::new(
+            Day::BankHoliday,
+            None,
+            Some(5),
+            None,
+            Some(1),
+            Some(vec![Weekday::Mon]),
+            0,
+        );
+
+        let actual: Vec<NaiveDate> = entry
+            .iter_between(
+                NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            )
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![
+                NaiveDate::from_ymd_opt(2022, 5, 2).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 5, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 5, 6).unwrap(),
+            ]
+        );
+    }
+
+    #[test_case(2024, 3, 31; "Easter Sunday 2024")]
+    #[test_case(2023, 4, 9; "Easter Sunday 2023")]
+    #[test_case(2000, 4, 23; "Easter Sunday 2000")]
+    #[test_case(1818, 3, 22; "Earliest possible Easter Sunday")]
+    #[test_case(1943, 4, 25; "Latest possible Easter Sunday")]
+    fn test_easter_sunday(year: i32, month: u32, day: u32) {
+        let actual = easter_sunday(year);
+
+        assert_eq!(actual, NaiveDate::from_ymd_opt(year, month, day).unwrap());
+    }
+
+    #[test_case(2024, 3, 29, Day::Holiday("Good Friday"); "Good Friday 2024")]
+    #[test_case(2024, 4, 1, Day::Holiday("Easter Monday"); "Easter Monday 2024")]
+    #[test_case(2023, 4, 7, Day::Holiday("Good Friday"); "Good Friday 2023")]
+    #[test_case(2024, 3, 30, Day::Workday; "Easter Saturday is not a movable feast")]
+    fn test_easter_offset_entries(year: i32, month: u32, day: u32, expected: Day) {
+        let mut subject = Calendar::<Day>::new();
+        subject.add(CalendarEntry::all(Day::Workday));
+        subject.add(CalendarEntry::easter_offset(
+            Day::Holiday("Good Friday"),
+            -2,
+        ));
+        subject.add(CalendarEntry::easter_offset(
+            Day::Holiday("Easter Monday"),
+            1,
+        ));
+
+        let actual = subject.classify_ymd(year, month, day);
+
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn test_easter_offset_composes_with_lieu_day_offset() {
+        let mut subject = Calendar::<Day>::new();
+        subject.add(CalendarEntry::all(Day::Workday));
+        subject.add(CalendarEntry::easter_offset(
+            Day::Holiday("Easter Monday"),
+            1,
+        ));
+        subject.add(CalendarEntry {
+            offset: 7,
+            ..CalendarEntry::easter_offset(Day::Holiday("Easter Monday lieu"), 1)
+        });
+
+        // Easter Monday 2024 is 2024-04-01; the lieu entry is offset by a
+        // week so that it fires on 2024-04-08 instead.
+        let actual = subject.classify_ymd(2024, 4, 8);
+
+        assert_eq!(actual, Some(Day::Holiday("Easter Monday lieu")));
+    }
+
+    #[test_case(2024, 5, 1, Weekday::Mon, 2024, 5, 6; "first Monday of May 2024, a 4-Monday month")]
+    #[test_case(2024, 5, -1, Weekday::Mon, 2024, 5, 27; "last Monday of May 2024, a 4-Monday month")]
+    #[test_case(2024, 5, -4, Weekday::Mon, 2024, 5, 6; "fourth-from-last Monday equals the first in a 4-Monday month")]
+    #[test_case(2021, 8, 1, Weekday::Mon, 2021, 8, 2; "first Monday of August 2021, a 5-Monday month")]
+    #[test_case(2021, 8, -1, Weekday::Mon, 2021, 8, 30; "last Monday of August 2021, a 5-Monday month")]
+    #[test_case(2021, 8, -5, Weekday::Mon, 2021, 8, 2; "fifth-from-last Monday equals the first in a 5-Monday month")]
+    #[test_case(2020, 3, 1, Weekday::Thu, 2020, 3, 5; "first Thursday of March 2020, a weekday other than week_start")]
+    #[test_case(2020, 3, -1, Weekday::Thu, 2020, 3, 26; "last Thursday of March 2020, a weekday other than week_start")]
+    fn test_nth_weekday(
+        year: i32,
+        month: u32,
+        n: i32,
+        weekday: Weekday,
+        ey: i32,
+        em: u32,
+        ed: u32,
+    ) {
+        let entry = CalendarEntry::nth_weekday(Day::BankHoliday, month, n, weekday);
+        let expected = NaiveDate::from_ymd_opt(ey, em, ed).unwrap();
+
+        let last_day = last_day_of_month(year, month);
+        for d in 1..=last_day {
+            let date = NaiveDate::from_ymd_opt(year, month, d).unwrap();
+            assert_eq!(entry.matches(date), date == expected, "checking {date}");
+        }
+    }
+
+    #[test]
+    fn test_nth_weekday_is_unaffected_by_week_start() {
+        let last_monday_of_august =
+            CalendarEntry::nth_weekday(Day::BankHoliday, 8, -1, Weekday::Mon)
+                .with_week_start(Weekday::Sun);
+        let expected = NaiveDate::from_ymd_opt(2021, 8, 30).unwrap();
+
+        assert!(last_monday_of_august.matches(expected));
+        assert!(!last_monday_of_august.matches(expected - Duration::days(7)));
+    }
+
+    #[test]
+    fn test_week_start_changes_which_week_a_plain_date_falls_in() {
+        // May 2024: 1st is a Wednesday, the first Sunday is the 5th and the
+        // first Monday is the 6th.
+        let week_two_from_monday =
+            CalendarEntry::new(Day::BankHoliday, None, Some(5), None, Some(2), None, 0);
+        let week_two_from_sunday =
+            CalendarEntry::new(Day::BankHoliday, None, Some(5), None, Some(2), None, 0)
+                .with_week_start(Weekday::Sun);
+
+        let may_6th = NaiveDate::from_ymd_opt(2024, 5, 6).unwrap();
+
+        assert!(!week_two_from_monday.matches(may_6th));
+        assert!(week_two_from_sunday.matches(may_6th));
+    }
+
+    #[test_case(2024, 4, 10, true; "Eid al-Fitr 1445 (1 Shawwal)")]
+    #[test_case(2024, 4, 9, false; "the day before doesn't match")]
+    #[test_case(2023, 7, 19, false; "1 Muharram isn't 1 Shawwal")]
+    fn test_entry_with_calendar_system_matches_in_that_system(
+        y: i32,
+        m: u32,
+        d: u32,
+        expected: bool,
+    ) {
+        let eid_al_fitr = CalendarEntry::ymd(Day::Holiday("Eid al-Fitr"), None, Some(10), Some(1))
+            .with_calendar_system(IslamicCalendar);
+        let date = NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(eid_al_fitr.matches(date), expected);
+    }
+
+    #[test]
+    fn test_calendar_system_defaults_to_gregorian() {
+        let christmas = CalendarEntry::ymd(Day::Holiday("Christmas"), None, Some(12), Some(25));
+
+        assert!(christmas.matches(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+    }
 }
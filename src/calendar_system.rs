@@ -0,0 +1,80 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+///
+/// Converts a Gregorian `NaiveDate` into another calendar system's
+/// `year`/`month`/`day`/`weekday`, so a [`CalendarEntry`](crate::CalendarEntry)
+/// can express its `year`/`month`/`day` in that system instead of the
+/// proleptic Gregorian calendar `chrono` uses natively.  Object-safe, so
+/// downstream crates (e.g. an ICU-backed converter) can supply their own
+/// systems as a `Box<dyn CalendarSystem>`.
+///
+pub trait CalendarSystem: std::fmt::Debug {
+    fn convert(&self, date: NaiveDate) -> (i32, u32, u32, Weekday);
+}
+
+///
+/// The proleptic Gregorian calendar, as used by `chrono`.  The default
+/// calendar system for every [`CalendarEntry`](crate::CalendarEntry).
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gregorian;
+
+impl CalendarSystem for Gregorian {
+    fn convert(&self, date: NaiveDate) -> (i32, u32, u32, Weekday) {
+        (date.year(), date.month(), date.day(), date.weekday())
+    }
+}
+
+///
+/// The tabular (civil) Islamic calendar: a fixed arithmetic approximation of
+/// the lunar Hijri calendar, used for holidays such as "1 Shawwal" (Eid
+/// al-Fitr) that can't be pinned to a Gregorian month/day. Being arithmetic
+/// rather than based on moon sighting, it can drift a day or two from
+/// locally observed dates.
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IslamicCalendar;
+
+impl CalendarSystem for IslamicCalendar {
+    fn convert(&self, date: NaiveDate) -> (i32, u32, u32, Weekday) {
+        // Richards' tabular Islamic calendar algorithm, via the Julian Day
+        // Number. `chrono`'s CE day count and JDN share a fixed offset.
+        let jdn = date.num_days_from_ce() as i64 + 1_721_425;
+
+        let l = jdn - 1_948_440 + 10632;
+        let n = (l - 1) / 10631;
+        let l = l - 10631 * n + 354;
+        let j = ((10985 - l) / 5316) * ((50 * l) / 17719) + (l / 5670) * ((43 * l) / 15238);
+        let l = l - ((30 - j) / 15) * ((17719 * j) / 50) - (j / 16) * ((15238 * j) / 43) + 29;
+        let month = (24 * l) / 709;
+        let day = l - (709 * month) / 24;
+        let year = 30 * n + j - 30;
+
+        (year as i32, month as u32, day as u32, date.weekday())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(622, 7, 19, 1, 1, 1; "the Islamic calendar epoch")]
+    #[test_case(2023, 7, 19, 1445, 1, 1; "Hijri New Year 1445")]
+    #[test_case(2024, 4, 10, 1445, 10, 1; "Eid al-Fitr 1445")]
+    #[test_case(2000, 1, 1, 1420, 9, 24; "the turn of the millennium")]
+    fn test_islamic_calendar_matches_known_correspondences(
+        gy: i32,
+        gm: u32,
+        gd: u32,
+        iy: i32,
+        im: u32,
+        id: u32,
+    ) {
+        let date = NaiveDate::from_ymd_opt(gy, gm, gd).unwrap();
+
+        let (year, month, day, _) = IslamicCalendar.convert(date);
+
+        assert_eq!((year, month, day), (iy, im, id));
+    }
+}
@@ -0,0 +1,283 @@
+use std::io;
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{Calendar, CalendarEntry};
+
+///
+/// A serializable description of a named calendar.  This lets an
+/// organisation ship a `bank-holidays.json` file describing its rules in
+/// plain language ("Good Friday", "Spring Bank Holiday") and load it at
+/// runtime instead of recompiling a `Calendar` built up in code.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CalendarSpec {
+    pub rules: Vec<RuleSpec>,
+}
+
+///
+/// A single named recurring rule within a [`CalendarSpec`].  Rules are
+/// expanded into one or more `CalendarEntry<String>` in the order they
+/// appear: later rules take priority over earlier ones, matching
+/// [`Calendar::add`]'s "further entries are more specific" convention.
+///
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RuleSpec {
+    /// The name this rule classifies matching dates as.
+    pub name: String,
+    /// Restrict this rule to a single year, if given.
+    pub year: Option<i32>,
+    /// How this rule recurs.
+    #[serde(flatten)]
+    pub recurrence: Recurrence,
+    /// The weekdays that trigger the lieu-day substitute below, if any.
+    pub substitute_on: Option<Vec<Weekday>>,
+    /// The offset, in days, applied when `substitute_on` matches, modelling
+    /// a replacement day in lieu of a holiday falling on a weekend.
+    pub substitute_offset: Option<i32>,
+}
+
+///
+/// How a [`RuleSpec`] recurs.
+///
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Recurrence {
+    /// A fixed month and day, e.g. 25 December.
+    MonthDay { month: u32, day: u32 },
+    /// The nth (or, if negative, last-from-end) weekday of a month, e.g.
+    /// the last Monday of May.
+    NthWeekday {
+        month: u32,
+        week_of_month: i32,
+        weekday: Weekday,
+    },
+    /// A fixed offset in days from Easter Sunday, e.g. `-2` for Good Friday.
+    Easter { offset: i32 },
+}
+
+impl RuleSpec {
+    /// Builds this rule's `CalendarEntry`, ignoring `substitute_on`/
+    /// `substitute_offset`. Called once per entry in `to_entries` so the
+    /// substitute entry gets a fresh, independent copy to override.
+    fn entry(&self) -> CalendarEntry<String> {
+        match &self.recurrence {
+            Recurrence::MonthDay { month, day } => {
+                CalendarEntry::ymd(self.name.clone(), self.year, Some(*month), Some(*day))
+            }
+            Recurrence::NthWeekday {
+                month,
+                week_of_month,
+                weekday,
+            } => CalendarEntry {
+                year: self.year,
+                ..CalendarEntry::nth_weekday(self.name.clone(), *month, *week_of_month, *weekday)
+            },
+            Recurrence::Easter { offset } => CalendarEntry {
+                year: self.year,
+                ..CalendarEntry::easter_offset(self.name.clone(), *offset)
+            },
+        }
+    }
+
+    fn to_entries(&self) -> Vec<CalendarEntry<String>> {
+        let mut entries = vec![self.entry()];
+
+        if let (Some(weekdays), Some(offset)) = (&self.substitute_on, self.substitute_offset) {
+            entries.push(CalendarEntry {
+                days_of_week: Some(weekdays.clone()),
+                offset,
+                ..self.entry()
+            });
+        }
+
+        entries
+    }
+}
+
+impl From<CalendarSpec> for Calendar<String> {
+    fn from(spec: CalendarSpec) -> Self {
+        let mut calendar = Calendar::new();
+        for rule in &spec.rules {
+            for entry in rule.to_entries() {
+                calendar.add(entry);
+            }
+        }
+        calendar
+    }
+}
+
+impl Calendar<String> {
+    ///
+    /// Loads a [`CalendarSpec`] as JSON from `reader` and expands its named
+    /// recurring rules into concrete calendar entries, so an organisation
+    /// can ship a `bank-holidays.json` file and load it at runtime instead
+    /// of recompiling.
+    ///
+    pub fn from_reader<R: io::Read>(reader: R) -> serde_json::Result<Self> {
+        let spec: CalendarSpec = serde_json::from_reader(reader)?;
+        Ok(spec.into())
+    }
+
+    ///
+    /// Writes this calendar's expanded entries out as JSON.  Note this is
+    /// not the inverse of `from_reader`: it serializes the `CalendarEntry`
+    /// list the rules expanded into, not the original named rules.
+    ///
+    pub fn to_writer<W: io::Write>(&self, writer: W) -> serde_json::Result<()> {
+        serde_json::to_writer(writer, self)
+    }
+
+    ///
+    /// Loads a [`CalendarSpec`] as YAML from `reader` and expands its named
+    /// recurring rules into concrete calendar entries, so an organisation
+    /// can ship a `bank-holidays.yaml` file and load it at runtime instead
+    /// of recompiling.
+    ///
+    /// Requires the `serde_yaml` crate as a dependency; add it to this
+    /// crate's manifest alongside `serde`/`serde_json`/`chrono` if it isn't
+    /// declared there already.
+    ///
+    pub fn from_yaml_reader<R: io::Read>(reader: R) -> serde_yaml::Result<Self> {
+        let spec: CalendarSpec = serde_yaml::from_reader(reader)?;
+        Ok(spec.into())
+    }
+
+    ///
+    /// Writes this calendar's expanded entries out as YAML.  Note this is
+    /// not the inverse of `from_yaml_reader`: it serializes the
+    /// `CalendarEntry` list the rules expanded into, not the original named
+    /// rules.
+    ///
+    pub fn to_yaml_writer<W: io::Write>(&self, writer: W) -> serde_yaml::Result<()> {
+        serde_yaml::to_writer(writer, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uk_bank_holidays_json() -> &'static str {
+        r#"{
+            "rules": [
+                { "name": "New Year's Day", "kind": "month_day", "month": 1, "day": 1 },
+                { "name": "Good Friday", "kind": "easter", "offset": -2 },
+                {
+                    "name": "New Year's Day",
+                    "kind": "month_day",
+                    "month": 1,
+                    "day": 1,
+                    "substitute_on": ["Sat", "Sun"],
+                    "substitute_offset": 2
+                }
+            ]
+        }"#
+    }
+
+    fn uk_bank_holidays_yaml() -> &'static str {
+        r#"
+        rules:
+          - name: New Year's Day
+            kind: month_day
+            month: 1
+            day: 1
+          - name: Good Friday
+            kind: easter
+            offset: -2
+          - name: New Year's Day
+            kind: month_day
+            month: 1
+            day: 1
+            substitute_on: [Sat, Sun]
+            substitute_offset: 2
+        "#
+    }
+
+    #[test]
+    fn test_from_reader_expands_named_rules_into_entries() {
+        let calendar = Calendar::from_reader(uk_bank_holidays_json().as_bytes()).unwrap();
+
+        assert_eq!(
+            calendar.classify_ymd(2024, 1, 1),
+            Some("New Year's Day".to_string())
+        );
+        assert_eq!(
+            calendar.classify_ymd(2023, 4, 7),
+            Some("Good Friday".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_reader_expands_substitute_day() {
+        // New Year's Day 2022 falls on a Saturday, so the substitute rule
+        // should move the holiday to the following Monday.
+        let calendar = Calendar::from_reader(uk_bank_holidays_json().as_bytes()).unwrap();
+
+        assert_eq!(
+            calendar.classify_ymd(2022, 1, 3),
+            Some("New Year's Day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_reader_expands_nth_weekday_rule_for_a_non_monday_weekday() {
+        let spec = r#"{
+            "rules": [
+                { "name": "First Thursday", "kind": "nth_weekday", "month": 3, "week_of_month": 1, "weekday": "Thu" }
+            ]
+        }"#;
+        let calendar = Calendar::from_reader(spec.as_bytes()).unwrap();
+
+        assert_eq!(
+            calendar.classify_ymd(2020, 3, 5),
+            Some("First Thursday".to_string())
+        );
+        assert_eq!(calendar.classify_ymd(2020, 3, 4), None);
+    }
+
+    #[test]
+    fn test_to_writer_round_trips_through_serde_json() {
+        let calendar = Calendar::from_reader(uk_bank_holidays_json().as_bytes()).unwrap();
+
+        let mut buf = Vec::new();
+        calendar.to_writer(&mut buf).unwrap();
+
+        let reloaded: Calendar<String> = serde_json::from_slice(&buf).unwrap();
+
+        assert_eq!(
+            reloaded.classify_ymd(2024, 1, 1),
+            Some("New Year's Day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_from_yaml_reader_expands_named_rules_into_entries() {
+        let calendar = Calendar::from_yaml_reader(uk_bank_holidays_yaml().as_bytes()).unwrap();
+
+        assert_eq!(
+            calendar.classify_ymd(2024, 1, 1),
+            Some("New Year's Day".to_string())
+        );
+        assert_eq!(
+            calendar.classify_ymd(2023, 4, 7),
+            Some("Good Friday".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to_yaml_writer_round_trips_through_serde_yaml() {
+        let calendar = Calendar::from_yaml_reader(uk_bank_holidays_yaml().as_bytes()).unwrap();
+
+        let mut buf = Vec::new();
+        calendar.to_yaml_writer(&mut buf).unwrap();
+
+        let reloaded: Calendar<String> = serde_yaml::from_slice(&buf).unwrap();
+
+        assert_eq!(
+            reloaded.classify_ymd(2024, 1, 1),
+            Some("New Year's Day".to_string())
+        );
+    }
+}